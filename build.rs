@@ -0,0 +1,32 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+// Expose the version of the MongoDB driver crate which was resolved for the build to the code as
+// the 'MONGODB_DRIVER_VERSION' env var, read from the lock file as Cargo doesn't provide the
+// versions of dependencies to the crates which depend on them
+//
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string());
+    let lock_file = Path::new(&manifest_dir).join("Cargo.lock");
+    println!("cargo:rerun-if-changed={}", lock_file.display());
+    let version = fs::read_to_string(&lock_file)
+        .ok()
+        .and_then(|contents| find_package_version(&contents, "mongodb"))
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=MONGODB_DRIVER_VERSION={}", version);
+}
+
+// Find the version of a package in the contents of a lock file, where each package's version
+// immediately follows its name
+//
+fn find_package_version(contents: &str, package: &str) -> Option<String> {
+    let name_line = format!("name = \"{}\"", package);
+    let mut lines = contents.lines().map(str::trim);
+    lines.find(|line| *line == name_line)?;
+    lines
+        .next()
+        .and_then(|line| line.strip_prefix("version = \""))
+        .and_then(|rest| rest.strip_suffix('"'))
+        .map(str::to_string)
+}